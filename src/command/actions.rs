@@ -0,0 +1,114 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    error::RunnerErrorKind,
+    webdriver::{Element, Locator, Webdriver},
+};
+
+use super::Command;
+
+pub struct DoubleClick {
+    target: Locator,
+}
+
+impl DoubleClick {
+    pub fn new(target: Locator) -> Self {
+        Self { target }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for DoubleClick {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let element = runner.get_webdriver().find(self.target.clone()).await?;
+        let _ = element.double_click().await?;
+
+        Ok(())
+    }
+}
+
+pub struct ContextClick {
+    target: Locator,
+}
+
+impl ContextClick {
+    pub fn new(target: Locator) -> Self {
+        Self { target }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for ContextClick {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let element = runner.get_webdriver().find(self.target.clone()).await?;
+        let _ = element.context_click().await?;
+
+        Ok(())
+    }
+}
+
+pub struct MouseOver {
+    target: Locator,
+}
+
+impl MouseOver {
+    pub fn new(target: Locator) -> Self {
+        Self { target }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for MouseOver {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let element = runner.get_webdriver().find(self.target.clone()).await?;
+        let _ = element.mouse_over().await?;
+
+        Ok(())
+    }
+}
+
+pub struct DragAndDrop {
+    source: Locator,
+    target: Locator,
+}
+
+impl DragAndDrop {
+    pub fn new(source: Locator, target: Locator) -> Self {
+        Self { source, target }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for DragAndDrop {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        runner
+            .get_webdriver()
+            .drag_and_drop(self.source.clone(), self.target.clone())
+            .await?;
+
+        Ok(())
+    }
+}
+
+pub struct SendKeys {
+    target: Locator,
+    keys: String,
+}
+
+impl SendKeys {
+    pub fn new(target: Locator, keys: String) -> Self {
+        Self { target, keys }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for SendKeys {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let element = runner.get_webdriver().find(self.target.clone()).await?;
+        let _ = element.send_keys(&self.keys).await?;
+
+        Ok(())
+    }
+}
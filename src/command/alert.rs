@@ -0,0 +1,116 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{error::RunnerErrorKind, webdriver::Webdriver};
+
+use super::Command;
+
+pub struct AcceptAlert;
+
+impl AcceptAlert {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for AcceptAlert {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        runner.get_webdriver().accept_alert().await?;
+
+        Ok(())
+    }
+}
+
+pub struct DismissAlert;
+
+impl DismissAlert {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for DismissAlert {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        runner.get_webdriver().dismiss_alert().await?;
+
+        Ok(())
+    }
+}
+
+pub struct AnswerPrompt {
+    answer: String,
+}
+
+impl AnswerPrompt {
+    pub fn new(answer: String) -> Self {
+        Self { answer }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for AnswerPrompt {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        runner
+            .get_webdriver()
+            .send_alert_text(&self.answer)
+            .await?;
+        runner.get_webdriver().accept_alert().await?;
+
+        Ok(())
+    }
+}
+
+pub struct AssertConfirmation {
+    text: String,
+}
+
+impl AssertConfirmation {
+    pub fn new(text: String) -> Self {
+        Self { text }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for AssertConfirmation {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let alert = runner.get_webdriver().alert_text().await?;
+
+        if alert == self.text {
+            Ok(())
+        } else {
+            Err(RunnerErrorKind::AssertFailed {
+                lhs: alert,
+                rhs: self.text.clone(),
+            })
+        }
+    }
+}
+
+pub struct AssertPrompt {
+    text: String,
+}
+
+impl AssertPrompt {
+    pub fn new(text: String) -> Self {
+        Self { text }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for AssertPrompt {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let alert = runner.get_webdriver().alert_text().await?;
+
+        if alert == self.text {
+            Ok(())
+        } else {
+            Err(RunnerErrorKind::AssertFailed {
+                lhs: alert,
+                rhs: self.text.clone(),
+            })
+        }
+    }
+}
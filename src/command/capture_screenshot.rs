@@ -0,0 +1,40 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::path::PathBuf;
+
+use crate::{
+    error::RunnerErrorKind,
+    webdriver::{Element, Locator, Webdriver},
+};
+
+use super::Command;
+
+pub struct CaptureScreenshot {
+    target: Option<Locator>,
+    output: PathBuf,
+}
+
+impl CaptureScreenshot {
+    pub fn new(target: Option<Locator>, output: PathBuf) -> Self {
+        Self { target, output }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for CaptureScreenshot {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let png = match &self.target {
+            Some(locator) => {
+                let mut element = runner.get_webdriver().find(locator.clone()).await?;
+                element.screenshot().await?
+            }
+            None => runner.get_webdriver().screenshot().await?,
+        };
+
+        std::fs::write(&self.output, png).map_err(|e| RunnerErrorKind::Io(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    error::RunnerErrorKind,
+    webdriver::{Cookie, Webdriver},
+};
+
+use super::Command;
+
+pub struct GetCookies {
+    variable: String,
+}
+
+impl GetCookies {
+    pub fn new(variable: String) -> Self {
+        Self { variable }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for GetCookies {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let cookies = runner.get_webdriver().get_cookies().await?;
+        let values = cookies
+            .into_iter()
+            .map(|c| serde_json::json!({ "name": c.name, "value": c.value }))
+            .collect::<Vec<_>>();
+
+        runner.save_value(self.variable.clone(), serde_json::Value::Array(values));
+
+        Ok(())
+    }
+}
+
+pub struct GetNamedCookie {
+    name: String,
+    variable: String,
+}
+
+impl GetNamedCookie {
+    pub fn new(name: String, variable: String) -> Self {
+        Self { name, variable }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for GetNamedCookie {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let cookie = runner.get_webdriver().get_named_cookie(&self.name).await?;
+
+        runner.save_value(self.variable.clone(), cookie.value.into());
+
+        Ok(())
+    }
+}
+
+pub struct AddCookie {
+    cookie: Cookie,
+}
+
+impl AddCookie {
+    pub fn new(cookie: Cookie) -> Self {
+        Self { cookie }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for AddCookie {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        runner.get_webdriver().add_cookie(self.cookie.clone()).await?;
+
+        Ok(())
+    }
+}
+
+pub struct DeleteCookie {
+    name: String,
+}
+
+impl DeleteCookie {
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for DeleteCookie {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        runner.get_webdriver().delete_cookie(&self.name).await?;
+
+        Ok(())
+    }
+}
+
+pub struct DeleteAllCookies;
+
+impl DeleteAllCookies {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for DeleteAllCookies {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        runner.get_webdriver().delete_all_cookies().await?;
+
+        Ok(())
+    }
+}
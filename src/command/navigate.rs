@@ -0,0 +1,58 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{error::RunnerErrorKind, webdriver::Webdriver};
+
+use super::Command;
+
+pub struct Back;
+
+impl Back {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for Back {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        runner.get_webdriver().back().await?;
+
+        Ok(())
+    }
+}
+
+pub struct Forward;
+
+impl Forward {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for Forward {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        runner.get_webdriver().forward().await?;
+
+        Ok(())
+    }
+}
+
+pub struct Refresh;
+
+impl Refresh {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for Refresh {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        runner.get_webdriver().refresh().await?;
+
+        Ok(())
+    }
+}
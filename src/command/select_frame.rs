@@ -0,0 +1,58 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    error::RunnerErrorKind,
+    webdriver::{Locator, Webdriver},
+};
+
+use super::Command;
+
+pub enum FrameTarget {
+    Index(u16),
+    Locator(Locator),
+}
+
+pub struct SelectFrame {
+    target: FrameTarget,
+}
+
+impl SelectFrame {
+    pub fn new(target: FrameTarget) -> Self {
+        Self { target }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for SelectFrame {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        match &self.target {
+            FrameTarget::Index(index) => {
+                runner.get_webdriver().switch_to_frame_index(*index).await?
+            }
+            FrameTarget::Locator(locator) => {
+                runner.get_webdriver().switch_to_frame(locator.clone()).await?
+            }
+        };
+
+        Ok(())
+    }
+}
+
+pub struct SelectFrameParent;
+
+impl SelectFrameParent {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for SelectFrameParent {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        runner.get_webdriver().switch_to_parent_frame().await?;
+
+        Ok(())
+    }
+}
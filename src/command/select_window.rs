@@ -0,0 +1,82 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{error::RunnerErrorKind, webdriver::Webdriver};
+
+use super::Command;
+
+pub struct SelectWindow {
+    handle: String,
+}
+
+impl SelectWindow {
+    pub fn new(handle: String) -> Self {
+        Self { handle }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for SelectWindow {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        runner
+            .get_webdriver()
+            .switch_to_window(&self.handle)
+            .await?;
+
+        Ok(())
+    }
+}
+
+pub struct StoreWindowHandle {
+    variable: String,
+}
+
+impl StoreWindowHandle {
+    pub fn new(variable: String) -> Self {
+        Self { variable }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for StoreWindowHandle {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let handle = runner.get_webdriver().current_window_handle().await?;
+
+        runner.save_value(self.variable.clone(), handle.into());
+
+        Ok(())
+    }
+}
+
+pub enum WindowKind {
+    Tab,
+    Window,
+}
+
+pub struct NewWindow {
+    kind: WindowKind,
+    variable: Option<String>,
+}
+
+impl NewWindow {
+    pub fn new(kind: WindowKind, variable: Option<String>) -> Self {
+        Self { kind, variable }
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: Webdriver> Command<D> for NewWindow {
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let handle = match self.kind {
+            WindowKind::Tab => runner.get_webdriver().new_tab().await?,
+            WindowKind::Window => runner.get_webdriver().new_window().await?,
+        };
+
+        if let Some(var) = self.variable.as_ref() {
+            runner.save_value(var.clone(), handle.into());
+        }
+
+        Ok(())
+    }
+}
@@ -25,9 +25,11 @@ impl WaitForElementEditable {
 #[async_trait::async_trait]
 impl<D: Webdriver> Command<D> for WaitForElementEditable {
     async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let poll_interval = runner.poll_interval();
+
         runner
             .get_webdriver()
-            .wait_for_editable(self.target.clone(), self.timeout)
+            .wait_for_editable(self.target.clone(), self.timeout, poll_interval)
             .await
             .map_err(|_| RunnerErrorKind::Timeout("WaitForElementPresent".to_owned()))?;
 
@@ -49,9 +51,11 @@ impl WaitForElementNotEditable {
 #[async_trait::async_trait]
 impl<D: Webdriver> Command<D> for WaitForElementNotEditable {
     async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let poll_interval = runner.poll_interval();
+
         runner
             .get_webdriver()
-            .wait_for_not_editable(self.target.clone(), self.timeout)
+            .wait_for_not_editable(self.target.clone(), self.timeout, poll_interval)
             .await
             .map_err(|_| RunnerErrorKind::Timeout("WaitForElementPresent".to_owned()))?;
 
@@ -21,9 +21,11 @@ impl WaitForElementPresent {
 #[async_trait::async_trait]
 impl<D: Webdriver> Command<D> for WaitForElementPresent {
     async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let poll_interval = runner.poll_interval();
+
         runner
             .get_webdriver()
-            .wait_for_present(self.target.clone(), self.timeout)
+            .wait_for_present(self.target.clone(), self.timeout, poll_interval)
             .await
             .map_err(|_| RunnerErrorKind::Timeout("WaitForElementPresent".to_owned()))?;
 
@@ -11,7 +11,6 @@ use crate::{
 
 use super::Command;
 
-#[allow(dead_code)]
 pub struct WaitForElementVisible {
     target: Locator,
     timeout: Duration,
@@ -25,8 +24,15 @@ impl WaitForElementVisible {
 
 #[async_trait::async_trait]
 impl<D: Webdriver> Command<D> for WaitForElementVisible {
-    async fn run(&self, _: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
-        tokio::time::sleep(self.timeout).await;
+    async fn run(&self, runner: &mut crate::runner::Runner<D>) -> Result<(), RunnerErrorKind> {
+        let poll_interval = runner.poll_interval();
+
+        runner
+            .get_webdriver()
+            .wait_for_visible(self.target.clone(), self.timeout, poll_interval)
+            .await
+            .map_err(|_| RunnerErrorKind::Timeout("WaitForElementVisible".to_owned()))?;
+
         Ok(())
     }
 }
@@ -0,0 +1,28 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cookie {
+    pub name: String,
+    pub value: String,
+    pub path: Option<String>,
+    pub domain: Option<String>,
+    pub secure: bool,
+    pub http_only: bool,
+    pub expiry: Option<i64>,
+}
+
+impl Cookie {
+    pub fn new(name: String, value: String) -> Self {
+        Self {
+            name,
+            value,
+            path: None,
+            domain: None,
+            secure: false,
+            http_only: false,
+            expiry: None,
+        }
+    }
+}
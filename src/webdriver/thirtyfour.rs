@@ -4,7 +4,7 @@
 
 #![cfg(feature = "thirtyfour_backend")]
 
-use super::{Element, Locator, Webdriver};
+use super::{Cookie, Element, Locator, Webdriver};
 use serde_json::Value as Json;
 use std::time::Duration;
 use thirtyfour::{
@@ -45,13 +45,14 @@ impl<'a> Webdriver for Client<'a> {
         &mut self,
         locator: Locator,
         timeout: Duration,
+        poll_interval: Duration,
     ) -> Result<(), Self::Error> {
         let locator: By = (&locator).into();
         let (e, _) = elapsed_fn(
             self.0
                 .query(locator)
                 .and_displayed()
-                .wait(timeout, timeout / 3)
+                .wait(timeout, poll_interval)
                 .first(),
         )
         .await;
@@ -64,12 +65,13 @@ impl<'a> Webdriver for Client<'a> {
         &mut self,
         locator: Locator,
         timeout: Duration,
+        poll_interval: Duration,
     ) -> Result<(), Self::Error> {
         let locator: By = (&locator).into();
         let (e, _) = elapsed_fn(
             self.0
                 .query(locator)
-                .wait(timeout, timeout / 3)
+                .wait(timeout, poll_interval)
                 .not_exists(),
         )
         .await;
@@ -82,9 +84,10 @@ impl<'a> Webdriver for Client<'a> {
         &mut self,
         locator: Locator,
         timeout: Duration,
+        poll_interval: Duration,
     ) -> Result<(), Self::Error> {
         let locator: By = (&locator).into();
-        let (e, _) = elapsed_fn(self.0.query(locator).wait(timeout, timeout / 3).exists()).await;
+        let (e, _) = elapsed_fn(self.0.query(locator).wait(timeout, poll_interval).exists()).await;
         e?;
 
         Ok(())
@@ -94,12 +97,13 @@ impl<'a> Webdriver for Client<'a> {
         &mut self,
         locator: Locator,
         timeout: Duration,
+        poll_interval: Duration,
     ) -> Result<(), Self::Error> {
         let locator: By = (&locator).into();
         let (e, _) = elapsed_fn(
             self.0
                 .query(locator)
-                .wait(timeout, timeout / 3)
+                .wait(timeout, poll_interval)
                 .and_clickable()
                 .and_enabled()
                 .first(),
@@ -110,6 +114,27 @@ impl<'a> Webdriver for Client<'a> {
         Ok(())
     }
 
+    async fn wait_for_not_editable(
+        &mut self,
+        locator: Locator,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<(), Self::Error> {
+        let locator: By = (&locator).into();
+        let (e, _) = elapsed_fn(
+            self.0
+                .query(locator)
+                .wait(timeout, poll_interval)
+                .and_clickable()
+                .and_not_enabled()
+                .first(),
+        )
+        .await;
+        e?;
+
+        Ok(())
+    }
+
     async fn current_url(&mut self) -> Result<Url, Self::Error> {
         let url = self.0.current_url().await?;
         Ok(Url::parse(&url).unwrap())
@@ -138,6 +163,130 @@ impl<'a> Webdriver for Client<'a> {
         self.0.close().await?;
         Ok(())
     }
+
+    async fn window_handles(&mut self) -> Result<Vec<String>, Self::Error> {
+        let handles = self.0.windows().await?;
+        Ok(handles.into_iter().map(|h| h.to_string()).collect())
+    }
+
+    async fn current_window_handle(&mut self) -> Result<String, Self::Error> {
+        let handle = self.0.current_window_handle().await?;
+        Ok(handle.to_string())
+    }
+
+    async fn switch_to_window(&mut self, handle: &str) -> Result<(), Self::Error> {
+        self.0
+            .switch_to_window(thirtyfour::WindowHandle::from(handle.to_owned()))
+            .await?;
+        Ok(())
+    }
+
+    async fn switch_to_frame_index(&mut self, index: u16) -> Result<(), Self::Error> {
+        self.0.enter_frame(index).await?;
+        Ok(())
+    }
+
+    async fn switch_to_frame(&mut self, locator: Locator) -> Result<(), Self::Error> {
+        let by: By = (&locator).into();
+        let element = self.0.find_element(by).await?;
+        self.0.enter_frame_element(&element).await?;
+        Ok(())
+    }
+
+    async fn switch_to_parent_frame(&mut self) -> Result<(), Self::Error> {
+        self.0.enter_parent_frame().await?;
+        Ok(())
+    }
+
+    async fn new_tab(&mut self) -> Result<String, Self::Error> {
+        let handle = self.0.new_tab().await?;
+        Ok(handle.to_string())
+    }
+
+    async fn new_window(&mut self) -> Result<String, Self::Error> {
+        let handle = self.0.new_window().await?;
+        Ok(handle.to_string())
+    }
+
+    async fn get_cookies(&mut self) -> Result<Vec<Cookie>, Self::Error> {
+        let cookies = self.0.get_all_cookies().await?;
+        Ok(cookies.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_named_cookie(&mut self, name: &str) -> Result<Cookie, Self::Error> {
+        let cookie = self.0.get_named_cookie(name).await?;
+        Ok(cookie.into())
+    }
+
+    async fn add_cookie(&mut self, cookie: Cookie) -> Result<(), Self::Error> {
+        self.0.add_cookie(cookie.into()).await?;
+        Ok(())
+    }
+
+    async fn delete_cookie(&mut self, name: &str) -> Result<(), Self::Error> {
+        self.0.delete_cookie(name).await?;
+        Ok(())
+    }
+
+    async fn delete_all_cookies(&mut self) -> Result<(), Self::Error> {
+        self.0.delete_all_cookies().await?;
+        Ok(())
+    }
+
+    async fn alert_text(&mut self) -> Result<String, Self::Error> {
+        let text = self.0.get_alert_text().await?;
+        Ok(text)
+    }
+
+    async fn accept_alert(&mut self) -> Result<(), Self::Error> {
+        self.0.accept_alert().await?;
+        Ok(())
+    }
+
+    async fn dismiss_alert(&mut self) -> Result<(), Self::Error> {
+        self.0.dismiss_alert().await?;
+        Ok(())
+    }
+
+    async fn send_alert_text(&mut self, keys: &str) -> Result<(), Self::Error> {
+        self.0.send_alert_text(keys).await?;
+        Ok(())
+    }
+
+    async fn back(&mut self) -> Result<(), Self::Error> {
+        self.0.back().await?;
+        Ok(())
+    }
+
+    async fn forward(&mut self) -> Result<(), Self::Error> {
+        self.0.forward().await?;
+        Ok(())
+    }
+
+    async fn refresh(&mut self) -> Result<(), Self::Error> {
+        self.0.refresh().await?;
+        Ok(())
+    }
+
+    async fn drag_and_drop(&mut self, source: Locator, target: Locator) -> Result<(), Self::Error> {
+        let source: By = (&source).into();
+        let target: By = (&target).into();
+        let source = self.0.find_element(source).await?;
+        let target = self.0.find_element(target).await?;
+
+        self.0
+            .action_chain()
+            .drag_and_drop_element(&source, &target)
+            .perform()
+            .await?;
+
+        Ok(())
+    }
+
+    async fn screenshot(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let png = self.0.screenshot_as_png().await?;
+        Ok(png)
+    }
 }
 
 pub struct WebElement<'a>(thirtyfour::WebElement<'a>, &'a thirtyfour::WebDriver);
@@ -204,6 +353,148 @@ impl<'a> Element for WebElement<'a> {
 
         Ok(Client(self.1))
     }
+
+    async fn double_click(self) -> Result<Self::Driver, Self::Error> {
+        self.1
+            .action_chain()
+            .move_to_element_center(&self.0)
+            .double_click()
+            .perform()
+            .await?;
+
+        Ok(Client(self.1))
+    }
+
+    async fn context_click(self) -> Result<Self::Driver, Self::Error> {
+        self.1
+            .action_chain()
+            .move_to_element_center(&self.0)
+            .context_click()
+            .perform()
+            .await?;
+
+        Ok(Client(self.1))
+    }
+
+    async fn mouse_over(self) -> Result<Self::Driver, Self::Error> {
+        self.1
+            .action_chain()
+            .move_to_element_center(&self.0)
+            .perform()
+            .await?;
+
+        Ok(Client(self.1))
+    }
+
+    async fn send_keys(self, keys: &str) -> Result<Self::Driver, Self::Error> {
+        let mut chain = self.1.action_chain().move_to_element_center(&self.0).click();
+
+        for segment in parse_key_chord(keys) {
+            chain = match segment {
+                KeyChordSegment::Literal(text) => chain.send_keys(text),
+                KeyChordSegment::KeyDown(key) => chain.key_down(key),
+                KeyChordSegment::KeyUp(key) => chain.key_up(key),
+            };
+        }
+
+        chain.perform().await?;
+
+        Ok(Client(self.1))
+    }
+
+    async fn screenshot(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let png = self.0.screenshot_as_png().await?;
+        Ok(png)
+    }
+}
+
+enum KeyChordSegment<'a> {
+    Literal(&'a str),
+    KeyDown(thirtyfour::Key),
+    KeyUp(thirtyfour::Key),
+}
+
+/// Splits a `sendKeys` string into literal text and `${KEY_*}` tokens.
+///
+/// Modifier tokens (`${KEY_CTRL}`, `${KEY_SHIFT}`, `${KEY_ALT}`, `${KEY_META}`)
+/// toggle: the first occurrence is a key-down, the matching second occurrence
+/// is a key-up, mirroring Selenium IDE's `${KEY_CTRL}a${KEY_CTRL}` chord
+/// convention. Any other named key is a single down/up press.
+fn parse_key_chord(keys: &str) -> Vec<KeyChordSegment<'_>> {
+    let mut segments = Vec::new();
+    let mut held = std::collections::HashSet::new();
+    let mut rest = keys;
+
+    while let Some(start) = rest.find("${KEY_") {
+        if start > 0 {
+            segments.push(KeyChordSegment::Literal(&rest[..start]));
+        }
+
+        let tail = &rest[start..];
+        let end = match tail.find('}') {
+            Some(end) => end,
+            None => {
+                segments.push(KeyChordSegment::Literal(tail));
+                rest = "";
+                break;
+            }
+        };
+
+        let name = &tail[2..end];
+        rest = &tail[end + 1..];
+
+        let key = match key_from_name(name) {
+            Some(key) => key,
+            None => {
+                segments.push(KeyChordSegment::Literal(&tail[..=end]));
+                continue;
+            }
+        };
+
+        if is_modifier(name) {
+            if held.remove(name) {
+                segments.push(KeyChordSegment::KeyUp(key));
+            } else {
+                held.insert(name);
+                segments.push(KeyChordSegment::KeyDown(key));
+            }
+        } else {
+            segments.push(KeyChordSegment::KeyDown(key));
+            segments.push(KeyChordSegment::KeyUp(key));
+        }
+    }
+
+    if !rest.is_empty() {
+        segments.push(KeyChordSegment::Literal(rest));
+    }
+
+    segments
+}
+
+fn is_modifier(name: &str) -> bool {
+    matches!(name, "KEY_CTRL" | "KEY_SHIFT" | "KEY_ALT" | "KEY_META")
+}
+
+fn key_from_name(name: &str) -> Option<thirtyfour::Key> {
+    use thirtyfour::Key;
+
+    Some(match name {
+        "KEY_CTRL" => Key::Control,
+        "KEY_SHIFT" => Key::Shift,
+        "KEY_ALT" => Key::Alt,
+        "KEY_META" => Key::Meta,
+        "KEY_ENTER" => Key::Enter,
+        "KEY_TAB" => Key::Tab,
+        "KEY_ESCAPE" => Key::Escape,
+        "KEY_BACKSPACE" => Key::Backspace,
+        "KEY_DELETE" => Key::Delete,
+        "KEY_SPACE" => Key::Space,
+        "KEY_LEFT" => Key::Left,
+        "KEY_RIGHT" => Key::Right,
+        "KEY_UP" => Key::Up,
+        "KEY_DOWN" => Key::Down,
+        _ => return None,
+    })
 }
 
 async fn elapsed_fn<F, R>(foo: F) -> (R, Duration)
@@ -217,6 +508,38 @@ where
     (result, elapsed)
 }
 
+impl From<thirtyfour::Cookie> for Cookie {
+    fn from(cookie: thirtyfour::Cookie) -> Self {
+        Self {
+            name: cookie.name().to_owned(),
+            value: cookie.value().as_str().unwrap_or_default().to_owned(),
+            path: cookie.path().clone(),
+            domain: cookie.domain().clone(),
+            secure: cookie.secure().unwrap_or(false),
+            http_only: cookie.http_only().unwrap_or(false),
+            expiry: cookie.expiry().map(|e| e.unix_timestamp()),
+        }
+    }
+}
+
+impl From<Cookie> for thirtyfour::Cookie {
+    fn from(cookie: Cookie) -> Self {
+        let mut c = thirtyfour::Cookie::new(cookie.name, Json::String(cookie.value));
+        c.set_path(cookie.path);
+        c.set_domain(cookie.domain);
+        c.set_secure(Some(cookie.secure));
+        c.set_http_only(Some(cookie.http_only));
+        if let Some(expiry) = cookie
+            .expiry
+            .and_then(|e| time::OffsetDateTime::from_unix_timestamp(e).ok())
+        {
+            c.set_expiry(expiry);
+        }
+
+        c
+    }
+}
+
 impl<'a> Into<By<'a>> for &'a Locator {
     fn into(self) -> By<'a> {
         match self {